@@ -0,0 +1,206 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{GenericArgument, PathArguments, Type};
+
+use crate::input::{FieldConfig, Input, Shape, TimeUnit, TraitsToDerive};
+
+/// Generates the `impl ArrowField for #name { ... }` block for `input`.
+pub fn expand_field(input: &Input) -> TokenStream {
+    if !matches!(
+        input.traits_to_derive,
+        TraitsToDerive::FieldOnly | TraitsToDerive::All
+    ) {
+        return TokenStream::new();
+    }
+
+    let name = &input.name;
+    let (data_type, field_metadata, is_nullable) = match &input.shape {
+        Shape::Struct(_) if input.transparent => {
+            let inner = input.transparent_field();
+            let inner_ty = &inner.field.ty;
+            (
+                field_data_type(inner),
+                field_metadata(inner),
+                quote! { <#inner_ty as arrow2_convert::ArrowField>::is_nullable() },
+            )
+        }
+        Shape::Struct(fields) => {
+            let field_tokens = fields.iter().map(expand_struct_field);
+            (
+                quote! { arrow2::datatypes::DataType::Struct(vec![#(#field_tokens),*]) },
+                quote! { arrow2::datatypes::Metadata::default() },
+                quote! { false },
+            )
+        }
+        Shape::Enum(variants) => {
+            let variant_fields = variants.iter().map(expand_enum_variant_field);
+            (
+                quote! {
+                    arrow2::datatypes::DataType::Union(
+                        vec![#(#variant_fields),*],
+                        None,
+                        arrow2::datatypes::UnionMode::Dense,
+                    )
+                },
+                quote! { arrow2::datatypes::Metadata::default() },
+                quote! { false },
+            )
+        }
+    };
+
+    quote! {
+        impl arrow2_convert::ArrowField for #name {
+            #[inline]
+            fn data_type() -> arrow2::datatypes::DataType {
+                #data_type
+            }
+
+            #[inline]
+            fn is_nullable() -> bool {
+                #is_nullable
+            }
+
+            #[inline]
+            fn field_metadata() -> arrow2::datatypes::Metadata {
+                #field_metadata
+            }
+        }
+    }
+}
+
+fn expand_struct_field(config: &FieldConfig) -> TokenStream {
+    let name = config.arrow_name();
+    let ty = &config.field.ty;
+    let data_type = field_data_type(config);
+    let metadata = field_metadata(config);
+    quote! {
+        arrow2::datatypes::Field::new(#name, #data_type, <#ty as arrow2_convert::ArrowField>::is_nullable())
+            .with_metadata(#metadata)
+    }
+}
+
+fn expand_enum_variant_field(variant: &crate::input::EnumVariant) -> TokenStream {
+    use crate::input::EnumVariantKind;
+
+    let name = variant.ident.to_string();
+    match &variant.kind {
+        EnumVariantKind::Unit => quote! {
+            arrow2::datatypes::Field::new(#name, arrow2::datatypes::DataType::Boolean, true)
+        },
+        EnumVariantKind::Newtype(ty) => quote! {
+            <#ty as arrow2_convert::ArrowField>::field(#name)
+        },
+        EnumVariantKind::Struct(fields) => {
+            let sub_fields = fields.iter().map(|field| {
+                let field_name = field.ident.as_ref().unwrap().to_string();
+                let field_ty = &field.ty;
+                quote! { <#field_ty as arrow2_convert::ArrowField>::field(#field_name) }
+            });
+            quote! {
+                arrow2::datatypes::Field::new(
+                    #name,
+                    arrow2::datatypes::DataType::Struct(vec![#(#sub_fields),*]),
+                    false,
+                )
+            }
+        }
+    }
+}
+
+/// The `DataType` expression for a field, honouring `decimal`/`timestamp`/`large`
+/// overrides before falling back to the field type's own `ArrowField::data_type()`.
+fn field_data_type(config: &FieldConfig) -> TokenStream {
+    let ty = &config.field.ty;
+
+    if let Some((precision, scale)) = config.decimal {
+        return quote! { arrow2::datatypes::DataType::Decimal(#precision, #scale) };
+    }
+
+    if config.time_unit.is_some() || config.timezone.is_some() {
+        let unit = config
+            .time_unit
+            .as_ref()
+            .map(time_unit_tokens)
+            .unwrap_or_else(|| quote! { arrow2::datatypes::TimeUnit::Nanosecond });
+        let timezone = match &config.timezone {
+            Some(tz) => quote! { Some(#tz.to_string()) },
+            // keep the field type's own timezone (e.g. `"UTC"` for `DateTime<Utc>`)
+            None => quote! {
+                match <#ty as arrow2_convert::ArrowField>::data_type() {
+                    arrow2::datatypes::DataType::Timestamp(_, timezone) => timezone,
+                    _ => None,
+                }
+            },
+        };
+        return quote! { arrow2::datatypes::DataType::Timestamp(#unit, #timezone) };
+    }
+
+    if config.large {
+        if is_named_type(ty, "String") {
+            return quote! { arrow2::datatypes::DataType::LargeUtf8 };
+        }
+        if let Some(elem_ty) = vec_elem_type(ty) {
+            if is_named_type(elem_ty, "u8") {
+                return quote! { arrow2::datatypes::DataType::LargeBinary };
+            }
+            return quote! {
+                arrow2::datatypes::DataType::LargeList(Box::new(<#elem_ty as arrow2_convert::ArrowField>::field("item")))
+            };
+        }
+    }
+
+    quote! { <#ty as arrow2_convert::ArrowField>::data_type() }
+}
+
+/// The `Metadata` expression for a field: the parsed `metadata(...)` entries if
+/// any were given, otherwise the field type's own `ArrowField::field_metadata()`.
+fn field_metadata(config: &FieldConfig) -> TokenStream {
+    if config.metadata.is_empty() {
+        let ty = &config.field.ty;
+        quote! { <#ty as arrow2_convert::ArrowField>::field_metadata() }
+    } else {
+        let entries = config
+            .metadata
+            .iter()
+            .map(|(key, value)| quote! { (#key.to_string(), #value.to_string()) });
+        quote! { [#(#entries),*].into_iter().collect::<arrow2::datatypes::Metadata>() }
+    }
+}
+
+fn time_unit_tokens(unit: &TimeUnit) -> TokenStream {
+    match unit {
+        TimeUnit::Second => quote! { arrow2::datatypes::TimeUnit::Second },
+        TimeUnit::Millisecond => quote! { arrow2::datatypes::TimeUnit::Millisecond },
+        TimeUnit::Microsecond => quote! { arrow2::datatypes::TimeUnit::Microsecond },
+        TimeUnit::Nanosecond => quote! { arrow2::datatypes::TimeUnit::Nanosecond },
+    }
+}
+
+fn is_named_type(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == name)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn vec_elem_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Vec" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
@@ -1,5 +1,6 @@
 use proc_macro2::Span;
-use syn::{Data, DeriveInput, Field, Ident, Lit, Meta, MetaNameValue, Visibility};
+use syn::{Data, DeriveInput, Field, Fields, Ident, Lit, Meta, MetaNameValue, NestedMeta, Type, Visibility};
+use syn::spanned::Spanned;
 use proc_macro_error::abort;
 
 #[derive(PartialEq)]
@@ -10,28 +11,252 @@ pub enum TraitsToDerive {
     All
 }
 
+/// The shape of a single enum variant, in declaration order.
+///
+/// Declaration order is used as the variant's Arrow union discriminant, so
+/// reordering variants is a breaking change for any data already encoded.
+pub enum EnumVariantKind {
+    /// A unit variant, e.g. `Foo`. Encoded as a null/boolean child.
+    Unit,
+    /// A single-field tuple variant, e.g. `Foo(T)`. Encoded as `T`'s own field.
+    Newtype(Type),
+    /// A struct-like variant, e.g. `Foo { a: T, b: U }`. Encoded as a nested struct child.
+    Struct(Vec<Field>),
+}
+
+/// One variant of a Rust `enum` being derived, as a union child.
+pub struct EnumVariant {
+    pub ident: Ident,
+    /// Position of this variant in the source, used as the union's `i8` discriminant.
+    pub discriminant: i8,
+    pub kind: EnumVariantKind,
+}
+
+/// The fields or variants that make up the type being derived.
+pub enum Shape {
+    /// A `struct`, mapped to `DataType::Struct`, one [`FieldConfig`] per field.
+    Struct(Vec<FieldConfig>),
+    /// An `enum`, mapped to a dense `DataType::Union`.
+    Enum(Vec<EnumVariant>),
+}
+
+/// A struct field together with the per-field overrides parsed out of its
+/// `#[arrow2_convert(...)]` attributes.
+pub struct FieldConfig {
+    pub field: Field,
+    /// Overrides the emitted Arrow `Field` name, from `#[arrow2_convert(name = "...")]`.
+    pub name: Option<String>,
+    /// Overrides the `Decimal` precision/scale picked for `i128` fields, from
+    /// `#[arrow2_convert(decimal(precision, scale))]`.
+    pub decimal: Option<(usize, usize)>,
+    /// Requests the large variant of the default logical type (`LargeUtf8`,
+    /// `LargeBinary`, `LargeList`), from `#[arrow2_convert(large)]`.
+    pub large: bool,
+    /// Key/value pairs to attach to the generated `Field`'s metadata, from
+    /// `#[arrow2_convert(metadata(key = "value", ...))]`.
+    pub metadata: Vec<(String, String)>,
+    /// Overrides the `TimeUnit` of a `Timestamp` field, from
+    /// `#[arrow2_convert(timestamp(unit = "second" | "milli" | "micro" | "nano", ...))]`.
+    pub time_unit: Option<TimeUnit>,
+    /// Overrides the timezone of a `Timestamp` field, from
+    /// `#[arrow2_convert(timestamp(..., timezone = "..."))]`.
+    pub timezone: Option<String>,
+}
+
+/// The `TimeUnit` names accepted by `#[arrow2_convert(timestamp(unit = "..."))]`,
+/// mirroring `arrow2::datatypes::TimeUnit`.
+pub enum TimeUnit {
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl FieldConfig {
+    /// The Arrow field name: the `name` override if present, otherwise the Rust identifier.
+    pub fn arrow_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| self.field.ident.as_ref().unwrap().to_string())
+    }
+}
+
+/// Parses a field's `#[arrow2_convert(...)]` attributes into a [`FieldConfig`].
+fn parse_field_config(field: Field) -> FieldConfig {
+    let mut name = None;
+    let mut decimal = None;
+    let mut large = false;
+    let mut metadata = vec![];
+    let mut time_unit = None;
+    let mut timezone = None;
+
+    for attr in &field.attrs {
+        let meta = match attr.parse_meta() {
+            Ok(meta) if meta.path().is_ident("arrow2_convert") => meta,
+            _ => continue,
+        };
+        let list = match meta {
+            Meta::List(list) => list,
+            _ => abort!(meta.span(), "Expected #[arrow2_convert(...)]"),
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(string),
+                    ..
+                })) if path.is_ident("name") => {
+                    name = Some(string.value());
+                }
+                NestedMeta::Meta(Meta::List(inner)) if inner.path.is_ident("decimal") => {
+                    let args = inner
+                        .nested
+                        .iter()
+                        .map(|n| match n {
+                            NestedMeta::Lit(Lit::Int(i)) => i
+                                .base10_parse::<usize>()
+                                .unwrap_or_else(|_| abort!(i.span(), "Expected an integer literal")),
+                            _ => abort!(inner.span(), "Expected #[arrow2_convert(decimal(precision, scale))]"),
+                        })
+                        .collect::<Vec<_>>();
+                    match args.as_slice() {
+                        [precision, scale] => decimal = Some((*precision, *scale)),
+                        _ => abort!(inner.span(), "Expected #[arrow2_convert(decimal(precision, scale))]"),
+                    }
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("large") => {
+                    large = true;
+                }
+                NestedMeta::Meta(Meta::List(inner)) if inner.path.is_ident("metadata") => {
+                    for entry in &inner.nested {
+                        match entry {
+                            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(value),
+                                ..
+                            })) => {
+                                let key = path.get_ident().unwrap_or_else(|| {
+                                    abort!(path.span(), "Expected a plain identifier as a metadata key")
+                                });
+                                metadata.push((key.to_string(), value.value()));
+                            }
+                            _ => abort!(entry.span(), "Expected #[arrow2_convert(metadata(key = \"value\", ...))]"),
+                        }
+                    }
+                }
+                NestedMeta::Meta(Meta::List(inner)) if inner.path.is_ident("timestamp") => {
+                    for entry in &inner.nested {
+                        match entry {
+                            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(value),
+                                ..
+                            })) if path.is_ident("unit") => {
+                                time_unit = Some(match value.value().as_str() {
+                                    "second" => TimeUnit::Second,
+                                    "milli" => TimeUnit::Millisecond,
+                                    "micro" => TimeUnit::Microsecond,
+                                    "nano" => TimeUnit::Nanosecond,
+                                    other => abort!(value.span(), "Unexpected timestamp unit '{}', expected one of second, milli, micro, nano", other),
+                                });
+                            }
+                            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(value),
+                                ..
+                            })) if path.is_ident("timezone") => {
+                                timezone = Some(value.value());
+                            }
+                            _ => abort!(entry.span(), "Expected #[arrow2_convert(timestamp(unit = \"...\", timezone = \"...\"))]"),
+                        }
+                    }
+                }
+                _ => abort!(nested.span(), "Unexpected field attribute"),
+            }
+        }
+    }
+
+    FieldConfig {
+        field,
+        name,
+        decimal,
+        large,
+        metadata,
+        time_unit,
+        timezone,
+    }
+}
+
 /// Representing the struct we are deriving
 pub struct Input {
     /// The input struct name
     pub name: Ident,
     /// The traits to derive
     pub traits_to_derive: TraitsToDerive,
-    /// The list of fields in the struct
-    pub fields: Vec<Field>,
+    /// The fields or variants of the type being derived
+    pub shape: Shape,
     /// The struct overall visibility
     pub visibility: Visibility,
+    /// Set by `#[arrow2_convert(transparent)]`: the derived `ArrowField` impl delegates
+    /// to the single field's own `ArrowField` impl (with that field's own
+    /// `#[arrow2_convert(...)]` overrides applied), rather than wrapping it in a
+    /// one-column struct.
+    pub transparent: bool,
 }
 
 impl Input {
     pub fn new(input: DeriveInput) -> Input {
         let mut traits_to_derive = TraitsToDerive::All;
 
-        let fields = match input.data {
-            Data::Struct(s) => s.fields.iter().cloned().collect::<Vec<_>>(),
-            _ => abort!(input.ident.span(), "#[derive(ArrowField)] only supports structs."),
+        let shape = match input.data {
+            Data::Struct(s) => Shape::Struct(
+                s.fields
+                    .into_iter()
+                    .map(parse_field_config)
+                    .collect::<Vec<_>>(),
+            ),
+            Data::Enum(e) => {
+                if !input.generics.params.is_empty() {
+                    abort!(
+                        input.generics.span(),
+                        "#[derive(ArrowField)] does not support generic enums."
+                    );
+                }
+                Shape::Enum(
+                    e.variants
+                        .iter()
+                        .enumerate()
+                        .map(|(discriminant, variant)| {
+                            if discriminant > i8::MAX as usize {
+                                abort!(variant.ident.span(), "#[derive(ArrowField)] supports at most {} variants.", i8::MAX as usize + 1);
+                            }
+                            let kind = match &variant.fields {
+                                Fields::Unit => EnumVariantKind::Unit,
+                                Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+                                    EnumVariantKind::Newtype(unnamed.unnamed.first().unwrap().ty.clone())
+                                }
+                                Fields::Unnamed(_) => abort!(
+                                    variant.ident.span(),
+                                    "#[derive(ArrowField)] only supports tuple variants with a single field."
+                                ),
+                                Fields::Named(named) => {
+                                    EnumVariantKind::Struct(named.named.iter().cloned().collect::<Vec<_>>())
+                                }
+                            };
+                            EnumVariant {
+                                ident: variant.ident.clone(),
+                                discriminant: discriminant as i8,
+                                kind,
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+            _ => abort!(input.ident.span(), "#[derive(ArrowField)] only supports structs and enums."),
         };
 
         let mut derives: Vec<Ident> = vec![];
+        let mut transparent = false;
         for attr in input.attrs {
             if let Ok(meta) = attr.parse_meta() {
                 if meta.path().is_ident("arrow2_convert") {
@@ -45,34 +270,55 @@ impl Input {
                                     "field_only" | "serialize_only" | "deserialize_only" => {
                                         if traits_to_derive != TraitsToDerive::All {
                                             abort!(string.span(), "Only one of field_only, serialize-only or deserialize_only can be specified");
-                                        }                                    
+                                        }
 
                                         match value {
                                             "field_only" => { traits_to_derive = TraitsToDerive::FieldOnly; },
                                             "serialize_only" => { traits_to_derive = TraitsToDerive::SerializeOnly; },
                                             "deserialize_only" => { traits_to_derive = TraitsToDerive::DeserializeOnly; },
-                                            _ => panic!("Unexpected {}", value) // intentionally leave as panic since we should never get here                   
+                                            _ => panic!("Unexpected {}", value) // intentionally leave as panic since we should never get here
                                         }
                                     },
+                                    "transparent" => { transparent = true; },
                                     _ => abort!(string.span(), "Unexpected {}", value)
                                 }
                                 derives.push(Ident::new(value.trim(), Span::call_site()));
                             }
                         }
-                        _ =>  { 
-                            use syn::spanned::Spanned;
-                            abort!(meta.span(), "Unexpected attribute"); 
+                        Meta::List(list) => {
+                            for nested in list.nested {
+                                match nested {
+                                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("transparent") => {
+                                        transparent = true;
+                                    }
+                                    _ => abort!(nested.span(), "Unexpected attribute, expected #[arrow2_convert(transparent)]"),
+                                }
+                            }
+                        }
+                        _ =>  {
+                            abort!(meta.span(), "Unexpected attribute");
                         }
                     }
                 }
             }
         }
 
+        if transparent {
+            let field_count = match &shape {
+                Shape::Struct(fields) => fields.len(),
+                Shape::Enum(_) => abort!(input.ident.span(), "#[arrow2_convert(transparent)] only supports structs."),
+            };
+            if field_count != 1 {
+                abort!(input.ident.span(), "#[arrow2_convert(transparent)] only supports structs with exactly one field.");
+            }
+        }
+
         Input {
             name: input.ident,
-            fields,
+            shape,
             visibility: input.vis,
-            traits_to_derive
+            traits_to_derive,
+            transparent,
         }
     }
 
@@ -87,4 +333,12 @@ impl Input {
     pub fn iterator_name(&self) -> Ident {
         Ident::new(&format!("{}ArrayIterator", self.name), Span::call_site())
     }
+
+    /// The single wrapped field, when `self.transparent` is set.
+    pub fn transparent_field(&self) -> &FieldConfig {
+        match &self.shape {
+            Shape::Struct(fields) if fields.len() == 1 => &fields[0],
+            _ => panic!("transparent_field called on a non-transparent Input"),
+        }
+    }
 }
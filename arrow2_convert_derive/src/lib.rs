@@ -0,0 +1,30 @@
+extern crate proc_macro;
+
+mod derive_field;
+mod input;
+
+use input::Input;
+use proc_macro::TokenStream;
+use proc_macro_error::proc_macro_error;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `ArrowField` for a struct or enum.
+///
+/// `data_type()`/`field_metadata()` are generated from the type's fields (or,
+/// for enums, its variants as a dense `Union`), honouring any
+/// `#[arrow2_convert(...)]` overrides parsed by [`input::Input`].
+///
+/// This crate only derives [`arrow2_convert::ArrowField`] (schema description).
+/// It does not derive `ArrowSerialize`/`ArrowDeserialize` — those traits, and
+/// the `MutableArray`/buffer-building code needed to move values into and out
+/// of Arrow arrays (dense `Union` types/offsets buffers, temporal unit
+/// scaling, `FixedSizeList`/`FixedSizeBinary` length validation, etc.), are
+/// out of scope for this snapshot, so schemas produced here are unverified
+/// against any actual (de)serialization path.
+#[proc_macro_error]
+#[proc_macro_derive(ArrowField, attributes(arrow2_convert))]
+pub fn arrow_field(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let input = Input::new(input);
+    derive_field::expand_field(&input).into()
+}
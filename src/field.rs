@@ -1,12 +1,13 @@
-use arrow2::datatypes::{DataType, Field};
-use chrono::{NaiveDate,NaiveDateTime};
+use arrow2::datatypes::{DataType, Field, Metadata, TimeUnit};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 
 /// Trait implemented by all types that can be used as an Arrow field.
 /// 
 /// Implementations are provided for types already supported by the arrow2 crate:
-/// - numeric types: [`u8`], [`u16`], [`u32`], [`u64`], [`i8`], [`i16`], [`i32`], [`i64`], [`f32`], [`f64`]
+/// - numeric types: [`u8`], [`u16`], [`u32`], [`u64`], [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`f32`], [`f64`]
 /// - other types: [`bool`], [`String`]
-/// - temporal types: [`chrono::NaiveDate`], [`chrono::NaiveDateTime`]
+/// - temporal types: [`chrono::NaiveDate`], [`chrono::NaiveDateTime`], [`chrono::DateTime<chrono::Utc>`]
+/// - fixed-size arrays: `[T; N]` (as `FixedSizeList`), `[u8; N]` (as `FixedSizeBinary`)
 /// 
 /// Custom implementations can be provided for other types.
 /// 
@@ -23,6 +24,7 @@ pub trait ArrowField: Sized
     // for internal use
     fn field(name: &str) -> Field {
         Field::new(name, Self::data_type(), Self::is_nullable())
+            .with_metadata(Self::field_metadata())
     }
 
     #[inline]
@@ -30,6 +32,173 @@ pub trait ArrowField: Sized
     fn is_nullable() -> bool {
         false
     }
+
+    #[inline]
+    // for internal use
+    fn field_metadata() -> Metadata {
+        Metadata::default()
+    }
+
+    /// Checks that `field` matches this type's own schema, recursing into
+    /// `List`/`Struct`/`Union` children.
+    ///
+    /// Intended for validating a derived type's schema against one read from
+    /// an external source (e.g. a Parquet file or IPC stream) before attempting
+    /// to deserialize, so mismatches are reported with a field path instead of
+    /// panicking deep inside array casting.
+    fn validate_against(field: &Field) -> Result<(), SchemaMismatch> {
+        validate_data_type(field.name.clone(), &Self::data_type(), Self::is_nullable(), field)
+    }
+}
+
+/// Error returned by [`ArrowField::validate_against`] describing where, and how,
+/// a schema failed to match.
+///
+/// `expected`/`actual` are only meaningfully different when the data types
+/// themselves disagree; a pure nullability mismatch is reported via
+/// `expected_nullable`/`actual_nullable` instead, with `expected`/`actual` both
+/// set to the (otherwise matching) data type for context.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaMismatch {
+    /// Dot-separated path to the mismatched field, e.g. `"a.b.c"`.
+    pub path: String,
+    pub expected: DataType,
+    pub actual: DataType,
+    pub expected_nullable: bool,
+    pub actual_nullable: bool,
+}
+
+impl std::fmt::Display for SchemaMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expected_nullable != self.actual_nullable {
+            write!(
+                f,
+                "schema mismatch at '{}': expected nullable={}, found nullable={} (data type {:?})",
+                self.path, self.expected_nullable, self.actual_nullable, self.expected
+            )
+        } else {
+            write!(
+                f,
+                "schema mismatch at '{}': expected {:?}, found {:?}",
+                self.path, self.expected, self.actual
+            )
+        }
+    }
+}
+
+impl std::error::Error for SchemaMismatch {}
+
+/// Builds a [`SchemaMismatch`] for a data-type disagreement at `path`, with
+/// nullability recorded as matching (since a type mismatch is reported
+/// independently of nullability).
+fn type_mismatch(path: String, expected: &DataType, actual: &Field) -> SchemaMismatch {
+    SchemaMismatch {
+        path,
+        expected: expected.clone(),
+        actual: actual.data_type.clone(),
+        expected_nullable: actual.is_nullable,
+        actual_nullable: actual.is_nullable,
+    }
+}
+
+fn validate_data_type(
+    path: String,
+    expected: &DataType,
+    expected_nullable: bool,
+    actual: &Field,
+) -> Result<(), SchemaMismatch> {
+    if expected_nullable != actual.is_nullable {
+        return Err(SchemaMismatch {
+            path,
+            expected: expected.clone(),
+            actual: actual.data_type.clone(),
+            expected_nullable,
+            actual_nullable: actual.is_nullable,
+        });
+    }
+
+    match (expected, &actual.data_type) {
+        (DataType::List(expected_item), DataType::List(actual_item))
+        | (DataType::LargeList(expected_item), DataType::LargeList(actual_item)) => {
+            validate_data_type(
+                format!("{}.{}", path, actual_item.name),
+                &expected_item.data_type,
+                expected_item.is_nullable,
+                actual_item,
+            )
+        }
+        (
+            DataType::FixedSizeList(expected_item, expected_size),
+            DataType::FixedSizeList(actual_item, actual_size),
+        ) => {
+            if expected_size != actual_size {
+                return Err(type_mismatch(path, expected, actual));
+            }
+            validate_data_type(
+                format!("{}.{}", path, actual_item.name),
+                &expected_item.data_type,
+                expected_item.is_nullable,
+                actual_item,
+            )
+        }
+        (DataType::Struct(expected_fields), DataType::Struct(actual_fields)) => {
+            if expected_fields.len() != actual_fields.len() {
+                return Err(type_mismatch(path, expected, actual));
+            }
+            expected_fields
+                .iter()
+                .zip(actual_fields.iter())
+                .try_for_each(|(expected_field, actual_field)| {
+                    if expected_field.name != actual_field.name {
+                        return Err(type_mismatch(
+                            format!("{}.{}", path, actual_field.name),
+                            expected,
+                            actual,
+                        ));
+                    }
+                    validate_data_type(
+                        format!("{}.{}", path, actual_field.name),
+                        &expected_field.data_type,
+                        expected_field.is_nullable,
+                        actual_field,
+                    )
+                })
+        }
+        (
+            DataType::Union(expected_fields, _, expected_mode),
+            DataType::Union(actual_fields, _, actual_mode),
+        ) => {
+            if expected_mode != actual_mode || expected_fields.len() != actual_fields.len() {
+                return Err(type_mismatch(path, expected, actual));
+            }
+            expected_fields
+                .iter()
+                .zip(actual_fields.iter())
+                .try_for_each(|(expected_field, actual_field)| {
+                    if expected_field.name != actual_field.name {
+                        return Err(type_mismatch(
+                            format!("{}.{}", path, actual_field.name),
+                            expected,
+                            actual,
+                        ));
+                    }
+                    validate_data_type(
+                        format!("{}.{}", path, actual_field.name),
+                        &expected_field.data_type,
+                        expected_field.is_nullable,
+                        actual_field,
+                    )
+                })
+        }
+        (expected, actual_data_type) if expected == actual_data_type => Ok(()),
+        (expected, actual_data_type) => Err(SchemaMismatch {
+            path,
+            expected: expected.clone(),
+            actual: actual_data_type.clone(),
+            expected_nullable: actual.is_nullable,
+            actual_nullable: actual.is_nullable,
+        }),
+    }
 }
 
 /// Enables the blanket implementations of [`Vec<T>`] as an Arrow field 
@@ -85,6 +254,17 @@ impl_numeric_type!(i64, Int64);
 impl_numeric_type!(f32, Float32);
 impl_numeric_type!(f64, Float64);
 
+// `i128` has no single canonical Arrow logical type, since `Decimal`'s precision
+// and scale can't be inferred from the Rust type alone. This default of
+// `Decimal(38, 0)` (the widest precision, no fractional digits) is used unless
+// a field overrides it via `#[arrow2_convert(decimal(precision, scale))]`.
+impl ArrowField for i128 {
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Decimal(38, 0)
+    }
+}
+
 impl ArrowField for String
 {
     #[inline]
@@ -101,11 +281,13 @@ impl ArrowField for bool
     }
 }
 
+// Defaults to nanosecond resolution and no timezone; override either with
+// `#[arrow2_convert(timestamp(unit = "...", timezone = "..."))]`.
 impl ArrowField for NaiveDateTime
 {
     #[inline]
     fn data_type() -> arrow2::datatypes::DataType {
-        arrow2::datatypes::DataType::Timestamp(arrow2::datatypes::TimeUnit::Nanosecond, None)
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
     }
 }
 
@@ -117,6 +299,16 @@ impl ArrowField for NaiveDate
     }
 }
 
+// Same override as [`NaiveDateTime`], with the timezone defaulting to `"UTC"`
+// instead of none, since `DateTime<Utc>` always carries a timezone.
+impl ArrowField for DateTime<Utc>
+{
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string()))
+    }
+}
+
 impl ArrowField for Vec<u8> {
     #[inline]
     fn data_type() -> arrow2::datatypes::DataType {
@@ -136,10 +328,43 @@ where T: ArrowField + ArrowEnableVecForType
     }
 }
 
+impl<const N: usize> ArrowField for [u8; N] {
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::FixedSizeBinary(N)
+    }
+}
+
+// Blanket implementation for fixed-size arrays, mirroring the `Vec<T>` blanket above.
+impl<T, const N: usize> ArrowField for [T; N]
+where T: ArrowField + ArrowEnableVecForType
+{
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::FixedSizeList(
+            Box::new(<T as ArrowField>::field("item")),
+            N,
+        )
+    }
+}
+
+// `u8` is deliberately excluded: it keeps the dedicated `Vec<u8>`/`[u8; N]`
+// (`Binary`/`FixedSizeBinary`) impls from conflicting with these blanket ones.
+arrow_enable_vec_for_type!(u16);
+arrow_enable_vec_for_type!(u32);
+arrow_enable_vec_for_type!(u64);
+arrow_enable_vec_for_type!(i8);
+arrow_enable_vec_for_type!(i16);
+arrow_enable_vec_for_type!(i32);
+arrow_enable_vec_for_type!(i64);
+arrow_enable_vec_for_type!(i128);
+arrow_enable_vec_for_type!(f32);
+arrow_enable_vec_for_type!(f64);
 arrow_enable_vec_for_type!(String);
 arrow_enable_vec_for_type!(bool);
 arrow_enable_vec_for_type!(NaiveDateTime);
 arrow_enable_vec_for_type!(NaiveDate);
+arrow_enable_vec_for_type!(DateTime<Utc>);
 arrow_enable_vec_for_type!(Vec<u8>);
 
 // Blanket implementation for Vec<Option<T>> if vectors are enabled for T
@@ -151,3 +376,16 @@ where T: ArrowField + ArrowEnableVecForType
 impl<T> ArrowEnableVecForType for Vec<T>
 where T: ArrowField + ArrowEnableVecForType,
 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_size_list_of_f32() {
+        assert_eq!(
+            <[f32; 4]>::data_type(),
+            DataType::FixedSizeList(Box::new(Field::new("item", DataType::Float32, false)), 4)
+        );
+    }
+}